@@ -0,0 +1,12 @@
+extern crate pkg_config;
+
+fn main() {
+    // `ped_partition_set_type_uuid`/`ped_partition_get_type_uuid` (GPT type
+    // GUIDs) only exist from libparted 3.4 onward; pkg-config only confirms
+    // the library is present, not which symbols it exports, so pin the
+    // version here rather than failing at link time.
+    pkg_config::Config::new()
+        .atleast_version("3.4")
+        .probe("libparted")
+        .expect("libparted >= 3.4 development headers not found (needed for GPT type-UUID support)");
+}
@@ -52,6 +52,9 @@ pub enum PartedError {
     #[fail(display = "unable to create new disk: {}", why)] CreateDisk { why: io::Error },
     #[fail(display = "unable to create new partition: {}", why)] CreatePartition { why: io::Error },
     #[fail(display = "unable to get exact constraint from geometry")] ExactConstraint,
+    #[fail(display = "unable to get optimal alignment constraint: {}", why)] AlignConstraint { why: io::Error },
+    #[fail(display = "unable to satisfy both the requested geometry and device alignment: {}", why)]
+    IntersectConstraint { why: io::Error },
     #[fail(display = "unable to add partition to disk: {}", why)] AddPartition { why: io::Error },
     #[fail(display = "unable to commit changes to disk: {}", why)] CommitChanges { why: io::Error },
     #[fail(display = "invalid file system type")] InvalidFileSystemType,
@@ -64,13 +67,13 @@ fn create_partition(device: &str, start: u64, length: Unit) -> Result<(), Parted
 
     // Get the sector length of the new partition.
     let length = match length {
-        Unit::Sectors(sectors) => sectors,
-        Unit::Mebibytes(m) => m * 1000 * 1000 / dev.sector_size(),
-        Unit::Megabytes(mb) => mb * 1024 * 1024 / dev.sector_size(),
+        Unit::Sectors(sectors) => sectors as i64,
+        Unit::Mebibytes(m) => dev.sectors_for_bytes(m * 1024 * 1024),
+        Unit::Megabytes(mb) => dev.sectors_for_bytes(mb * 1000 * 1000),
     };
 
-    let geometry = Geometry::new(&dev, start as i64, length as i64)
-        .map_err(|why| PartedError::CreateGeometry { why })?;
+    let geometry =
+        Geometry::new(&dev, start as i64, length).map_err(|why| PartedError::CreateGeometry { why })?;
     let mut disk = Disk::new(&mut dev).map_err(|why| PartedError::CreateDisk { why })?;
 
     // Create an unformatted file system type.
@@ -90,8 +93,15 @@ fn create_partition(device: &str, start: u64, length: Unit) -> Result<(), Parted
         let _ = partition.set_flag(PartitionFlag::PED_PARTITION_LBA, true);
     }
 
-    // Also get the exact constraints of the geometry.
-    let constraint = geometry.exact().ok_or(PartedError::ExactConstraint)?;
+    // Pin the partition to the requested geometry, but only where that also
+    // satisfies the device's optimal I/O alignment.
+    let exact = geometry.exact().ok_or(PartedError::ExactConstraint)?;
+    let aligned = dev
+        .optimal_aligned_constraint()
+        .map_err(|why| PartedError::AlignConstraint { why })?;
+    let constraint = exact
+        .intersect(&aligned)
+        .map_err(|why| PartedError::IntersectConstraint { why })?;
 
     // Add the partition to the disk, and set the corresponding partition flag.
     if let Err(why) = disk.add_partition(&mut partition, &constraint) {
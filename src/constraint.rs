@@ -0,0 +1,69 @@
+use std::io;
+
+use ffi::{self, PedConstraint};
+use Device;
+use Geometry;
+
+/// A set of restrictions on where a partition may be placed and how large it
+/// may be, as understood by `ped_disk_add_partition`.
+///
+/// Most constraints are obtained from another object (`Geometry::exact`, …)
+/// rather than constructed directly.
+pub struct Constraint(*mut PedConstraint);
+
+impl Constraint {
+    /// Wraps an already-allocated `PedConstraint`. The caller must have
+    /// checked it for null.
+    pub(crate) unsafe fn from_ptr(constraint: *mut PedConstraint) -> Constraint {
+        Constraint(constraint)
+    }
+
+    /// The raw `PedConstraint` pointer, for use by sibling modules in this crate.
+    pub(crate) fn as_ptr(&self) -> *const PedConstraint {
+        self.0
+    }
+
+    /// A constraint that permits a partition anywhere on `device`
+    /// (`ped_constraint_any`). Pair with a free-space region from
+    /// `Disk::free_space_regions` to let libparted pick an aligned placement
+    /// within it, rather than pinning an exact start and length.
+    pub fn any(device: &Device) -> io::Result<Constraint> {
+        let constraint = unsafe { ffi::ped_constraint_any(device.as_ptr_const()) };
+        if constraint.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Constraint(constraint))
+    }
+
+    /// A constraint that confines a partition to within `region`
+    /// (`ped_constraint_new_from_max`), letting libparted choose the actual
+    /// start and length so long as the result fits inside it.
+    pub fn within_region(region: &Geometry) -> io::Result<Constraint> {
+        let constraint = unsafe { ffi::ped_constraint_new_from_max(region.as_ptr()) };
+        if constraint.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Constraint(constraint))
+    }
+
+    /// The constraint that satisfies both `self` and `other`
+    /// (`ped_constraint_intersect`). Used to combine an alignment constraint
+    /// (from `Device::optimal_aligned_constraint`, …) with a geometry's exact
+    /// placement so a partition lands on a valid sector boundary.
+    pub fn intersect(&self, other: &Constraint) -> io::Result<Constraint> {
+        let constraint = unsafe { ffi::ped_constraint_intersect(self.0, other.0) };
+        if constraint.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Constraint(constraint))
+    }
+}
+
+impl Drop for Constraint {
+    fn drop(&mut self) {
+        unsafe { ffi::ped_constraint_destroy(self.0) }
+    }
+}
@@ -0,0 +1,171 @@
+use std::ffi::CString;
+use std::io;
+use std::path::Path;
+
+use ffi::{self, PedDevice};
+use Constraint;
+
+/// A handle to a block device, opened through libparted.
+///
+/// Dropping a `Device` closes it and releases the underlying `PedDevice`.
+pub struct Device(*mut PedDevice);
+
+impl Device {
+    /// Obtains a device from the OS by path, and opens it for I/O.
+    pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Device> {
+        let cstr = CString::new(path.as_ref().to_string_lossy().as_bytes())?;
+        let dev = unsafe { ffi::ped_device_get(cstr.as_ptr()) };
+        if dev.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        if unsafe { ffi::ped_device_open(dev) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Device(dev))
+    }
+
+    /// The raw `PedDevice` pointer, for use by sibling modules in this crate.
+    pub(crate) fn as_ptr(&mut self) -> *mut PedDevice {
+        self.0
+    }
+
+    /// The raw `PedDevice` pointer, for read-only use by sibling modules.
+    pub(crate) fn as_ptr_const(&self) -> *const PedDevice {
+        self.0
+    }
+
+    /// The logical sector size reported by the device, in bytes.
+    pub fn sector_size(&self) -> u64 {
+        unsafe { (*self.0).sector_size }
+    }
+
+    /// The physical sector size reported by the device, in bytes.
+    ///
+    /// On 4K-native drives this may be larger than `sector_size()`; partition
+    /// starts should be snapped to this grid, not the logical one.
+    pub fn phys_sector_size(&self) -> u64 {
+        unsafe { (*self.0).phys_sector_size }
+    }
+
+    /// The length of the device, in logical sectors.
+    pub fn length(&self) -> i64 {
+        unsafe { (*self.0).length }
+    }
+
+    /// Rounds `bytes` up to the nearest whole number of logical sectors
+    /// (`sector_size()`), for callers that size partitions in bytes rather
+    /// than sectors.
+    pub fn sectors_for_bytes(&self, bytes: u64) -> i64 {
+        bytes.div_ceil(self.sector_size()) as i64
+    }
+
+    /// A constraint that keeps a partition aligned to the device's optimal
+    /// I/O boundaries (`ped_device_get_optimal_aligned_constraint`).
+    pub fn optimal_aligned_constraint(&mut self) -> io::Result<Constraint> {
+        let constraint = unsafe { ffi::ped_device_get_optimal_aligned_constraint(self.0) };
+        if constraint.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(unsafe { Constraint::from_ptr(constraint) })
+    }
+
+    /// A constraint that keeps a partition aligned to the device's minimal
+    /// (physical-sector) boundaries (`ped_device_get_minimal_aligned_constraint`).
+    pub fn minimal_aligned_constraint(&mut self) -> io::Result<Constraint> {
+        let constraint = unsafe { ffi::ped_device_get_minimal_aligned_constraint(self.0) };
+        if constraint.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(unsafe { Constraint::from_ptr(constraint) })
+    }
+
+    /// Reads `count` sectors starting at `start` into `buffer`
+    /// (`ped_device_read`). `buffer` must be at least `count * sector_size()`
+    /// bytes.
+    pub fn read_sectors(&mut self, start: i64, count: i64, buffer: &mut [u8]) -> io::Result<()> {
+        let result = unsafe {
+            ffi::ped_device_read(self.0, buffer.as_mut_ptr() as *mut _, start, count)
+        };
+        if result == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// Writes `count` sectors from `buffer` starting at `start`
+    /// (`ped_device_write`). `buffer` must hold at least
+    /// `count * sector_size()` bytes.
+    pub fn write_sectors(&mut self, start: i64, count: i64, buffer: &[u8]) -> io::Result<()> {
+        let result = unsafe {
+            ffi::ped_device_write(self.0, buffer.as_ptr() as *const _, start, count)
+        };
+        if result == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// The logical sector size of a raw, borrowed `PedDevice` pointer. See
+    /// `read_sectors_raw`.
+    pub(crate) unsafe fn sector_size_raw(dev: *mut PedDevice) -> u64 {
+        (*dev).sector_size
+    }
+
+    /// `optimal_aligned_constraint`, against a raw, borrowed `PedDevice`
+    /// pointer that this `Device` does not own. Used internally by
+    /// `Disk::clone_partition_to`.
+    pub(crate) unsafe fn optimal_aligned_constraint_raw(dev: *mut PedDevice) -> io::Result<Constraint> {
+        let constraint = ffi::ped_device_get_optimal_aligned_constraint(dev);
+        if constraint.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Constraint::from_ptr(constraint))
+    }
+
+    /// Reads `count` sectors directly through a raw `PedDevice` pointer that
+    /// this `Device` does not own (e.g. one borrowed from another disk's
+    /// `dev` field). Used internally by `Disk::clone_partition_to`.
+    pub(crate) unsafe fn read_sectors_raw(
+        dev: *mut PedDevice,
+        start: i64,
+        count: i64,
+        buffer: &mut [u8],
+    ) -> io::Result<()> {
+        if ffi::ped_device_read(dev, buffer.as_mut_ptr() as *mut _, start, count) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// Writes `count` sectors directly through a raw `PedDevice` pointer that
+    /// this `Device` does not own. See `read_sectors_raw`.
+    pub(crate) unsafe fn write_sectors_raw(
+        dev: *mut PedDevice,
+        start: i64,
+        count: i64,
+        buffer: &[u8],
+    ) -> io::Result<()> {
+        if ffi::ped_device_write(dev, buffer.as_ptr() as *const _, start, count) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::ped_device_close(self.0);
+            ffi::ped_device_destroy(self.0);
+        }
+    }
+}
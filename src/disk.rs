@@ -0,0 +1,294 @@
+use std::io;
+use std::ptr;
+
+use libc;
+
+use ffi::{self, PedDevice, PedDisk};
+use Constraint;
+use Device;
+use DiskType;
+use Geometry;
+use Partition;
+use PartitionFlag;
+use PartitionType;
+
+/// A disk's partition table, as read from or freshly laid down on a `Device`.
+///
+/// Changes made through `add_partition` are only staged in memory; call
+/// `commit` to write them back to the device.
+pub struct Disk(*mut PedDisk);
+
+impl Disk {
+    /// Reads the existing partition table from `device`.
+    pub fn new(device: &mut Device) -> io::Result<Disk> {
+        let disk = unsafe { ffi::ped_disk_new(device.as_ptr()) };
+        if disk.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Disk(disk))
+    }
+
+    /// Creates a brand-new, empty partition table of `disk_type` on `device`,
+    /// discarding whatever label (if any) was there before. Nothing is
+    /// written to `device` until the result is passed to `commit`.
+    pub fn new_fresh(device: &mut Device, disk_type: &DiskType) -> io::Result<Disk> {
+        let disk = unsafe { ffi::ped_disk_new_fresh(device.as_ptr(), disk_type.as_ptr()) };
+        if disk.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Disk(disk))
+    }
+
+    /// The raw `PedDisk` pointer, for read-only use by sibling modules.
+    pub(crate) fn as_ptr_const(&self) -> *const PedDisk {
+        self.0
+    }
+
+    /// The raw `PedDevice` pointer this disk's table was read from or
+    /// created on, borrowed (not owned) from the `PedDisk` struct.
+    fn device_ptr(&self) -> *mut PedDevice {
+        unsafe { (*self.0).dev }
+    }
+
+    /// The disk label's required partition alignment
+    /// (`ped_disk_get_partition_alignment`), e.g. the 1 MiB alignment GPT
+    /// expects of the first usable sector. Intersect this with an exact or
+    /// aligned device constraint before calling `add_partition` to guarantee
+    /// the result satisfies both the label and the underlying sector size.
+    pub fn partition_alignment(&self) -> io::Result<Constraint> {
+        let constraint = unsafe { ffi::ped_disk_get_partition_alignment(self.0) };
+        if constraint.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(unsafe { Constraint::from_ptr(constraint) })
+    }
+
+    /// The unused regions of this disk's table, as `PED_PARTITION_FREESPACE`
+    /// entries (`ped_disk_next_partition`). Pair one with `Constraint::any` or
+    /// `Constraint::within_region` to place a new partition in the largest
+    /// gap without computing an exact start yourself.
+    pub fn free_space_regions(&self) -> io::Result<Vec<Geometry>> {
+        let mut regions = Vec::new();
+        let mut part = unsafe { ffi::ped_disk_next_partition(self.0, ptr::null()) };
+
+        while !part.is_null() {
+            if unsafe { (*part).part_type } & (PartitionType::PED_PARTITION_FREESPACE as i32) != 0 {
+                let geom = unsafe { ffi::ped_geometry_duplicate(&(*part).geom) };
+                if geom.is_null() {
+                    return Err(io::Error::last_os_error());
+                }
+
+                regions.push(unsafe { Geometry::from_ptr(geom) });
+            }
+
+            part = unsafe { ffi::ped_disk_next_partition(self.0, part) };
+        }
+
+        Ok(regions)
+    }
+
+    /// The real (non-freespace, non-metadata) partitions already in this
+    /// disk's table (`ped_disk_next_partition`), as borrowed `Partition`s:
+    /// they're owned by this `Disk` already, so dropping one doesn't free it.
+    /// Use this to get a source partition for `clone_partition_to`.
+    pub fn partitions(&self) -> Vec<Partition> {
+        let mut partitions = Vec::new();
+        let mut part = unsafe { ffi::ped_disk_next_partition(self.0, ptr::null()) };
+
+        while !part.is_null() {
+            let part_type = unsafe { (*part).part_type };
+            let skip = PartitionType::PED_PARTITION_FREESPACE as i32
+                | PartitionType::PED_PARTITION_METADATA as i32;
+            if part_type & skip == 0 {
+                partitions.push(unsafe { Partition::from_borrowed_ptr(part) });
+            }
+
+            part = unsafe { ffi::ped_disk_next_partition(self.0, part) };
+        }
+
+        partitions
+    }
+
+    /// Adds `partition` to the in-memory table, subject to `constraint`.
+    pub fn add_partition(&mut self, partition: &mut Partition, constraint: &Constraint) -> io::Result<()> {
+        let result =
+            unsafe { ffi::ped_disk_add_partition(self.0, partition.as_ptr_mut(), constraint.as_ptr()) };
+        if result == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // libparted now owns the partition; our `Partition` must not free it.
+        partition.mark_added();
+
+        Ok(())
+    }
+
+    /// Copies `part` from this disk onto `dest`, starting at sector `start`
+    /// of `dest`'s device: creates a new partition with the same type, name,
+    /// type GUID, and flags, adds it to `dest`'s in-memory table, then
+    /// streams the source partition's byte contents across in fixed-size
+    /// chunks. Neither disk is committed; call `commit` on `dest` once
+    /// satisfied.
+    ///
+    /// The new partition is placed at `start`, but only where that also
+    /// satisfies `dest`'s optimal I/O alignment; if the two can't be
+    /// reconciled, intersecting the constraints or `add_partition` fails and
+    /// nothing is written.
+    ///
+    /// `part`'s length is carried across as a byte count, not a sector count:
+    /// if `dest`'s logical sector size differs from this disk's, the new
+    /// partition is sized in `dest` sectors to hold at least that many bytes
+    /// (rounding up), and any bytes added by that rounding are zero-filled.
+    /// Transfers happen in chunks sized to a common multiple of both sector
+    /// sizes, so every read/write lands on a whole sector on both sides.
+    ///
+    /// The new partition is already owned by `dest` once this returns, so it
+    /// is not handed back to the caller — use `dest`'s own accessors (e.g.
+    /// `free_space_regions`, or rereading the table) to inspect it further.
+    pub fn clone_partition_to(&self, part: &Partition, dest: &mut Disk, start: i64) -> io::Result<()> {
+        let src_dev = self.device_ptr();
+        let dest_dev = dest.device_ptr();
+        let src_sector_size = unsafe { Device::sector_size_raw(src_dev) };
+        let dest_sector_size = unsafe { Device::sector_size_raw(dest_dev) };
+
+        let total_bytes = part.length() as u64 * src_sector_size;
+        let dest_length = total_bytes.div_ceil(dest_sector_size) as i64;
+
+        let geometry = unsafe { Geometry::new_raw(dest_dev, start, dest_length)? };
+        let exact = geometry
+            .exact()
+            .ok_or_else(|| io::Error::other("unable to get exact constraint"))?;
+        let aligned = unsafe { Device::optimal_aligned_constraint_raw(dest_dev)? };
+        let constraint = exact.intersect(&aligned)?;
+
+        let mut new_part = Partition::new(dest, part.part_type(), None, start, dest_length)?;
+        if let Some(name) = part.name() {
+            new_part.set_name(&name)?;
+        }
+        if let Some(uuid) = part.type_uuid() {
+            new_part.set_type_uuid(&uuid)?;
+        }
+        for &flag in PartitionFlag::ALL.iter() {
+            if part.is_flag_available(flag) && part.flag(flag) {
+                let _ = new_part.set_flag(flag, true);
+            }
+        }
+
+        dest.add_partition(&mut new_part, &constraint)?;
+
+        // Every chunk must be a whole number of sectors on both sides, so walk
+        // the transfer in multiples of the two sector sizes' lcm.
+        const TARGET_CHUNK_BYTES: u64 = 1024 * 1024;
+        let granularity = lcm(src_sector_size, dest_sector_size);
+        let chunk_bytes = granularity * (TARGET_CHUNK_BYTES / granularity).max(1);
+
+        let padded_total = dest_length as u64 * dest_sector_size;
+        let mut offset = 0u64;
+        let mut buffer = vec![0u8; chunk_bytes as usize];
+
+        while offset < padded_total {
+            let this_chunk = chunk_bytes.min(padded_total - offset);
+            let buf = &mut buffer[..this_chunk as usize];
+
+            let readable = total_bytes.saturating_sub(offset).min(this_chunk);
+            if readable > 0 {
+                let src_sector = part.start() as u64 + offset / src_sector_size;
+                let src_count = readable / src_sector_size;
+                unsafe {
+                    Device::read_sectors_raw(src_dev, src_sector as i64, src_count as i64, &mut buf[..readable as usize])?
+                };
+            }
+            for b in &mut buf[readable as usize..] {
+                *b = 0;
+            }
+
+            let dest_sector = start as u64 + offset / dest_sector_size;
+            let dest_count = this_chunk / dest_sector_size;
+            unsafe { Device::write_sectors_raw(dest_dev, dest_sector as i64, dest_count as i64, buf)? };
+
+            offset += this_chunk;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the in-memory table back to the device and asks the OS to
+    /// reread it. Equivalent to `commit_to_device()` followed by
+    /// `commit_to_os()`.
+    pub fn commit(&mut self) -> io::Result<()> {
+        if unsafe { ffi::ped_disk_commit(self.0) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// Writes the in-memory table to the device only, without telling the OS
+    /// to reread it (`ped_disk_commit_to_dev`). Pair with `commit_to_os` or
+    /// `reread` once the caller is ready for the kernel to see the new
+    /// layout.
+    pub fn commit_to_device(&mut self) -> io::Result<()> {
+        if unsafe { ffi::ped_disk_commit_to_dev(self.0) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// Tells the OS to reread the partition table already written to the
+    /// device, via `ped_disk_commit_to_os` (which itself issues `BLKRRPART`
+    /// on Linux). There is no separate direct-ioctl path: the file
+    /// descriptor `BLKRRPART` needs lives behind libparted's own
+    /// platform-specific device struct, which this crate doesn't bind, so
+    /// `ped_disk_commit_to_os` is the only way in rather than a fallback.
+    ///
+    /// On failure, reports a "device is busy" error if `errno` happens to be
+    /// `EBUSY` (e.g. a stale partition is still mounted). This is best-effort:
+    /// `ped_disk_commit_to_os` reports failure through libparted's own
+    /// exception handler rather than always leaving a meaningful `errno`
+    /// behind, so a busy device may still surface as the generic error below
+    /// instead of the typed one.
+    pub fn commit_to_os(&mut self) -> io::Result<()> {
+        if unsafe { ffi::ped_disk_commit_to_os(self.0) } == 0 {
+            let why = io::Error::last_os_error();
+            return Err(match why.raw_os_error() {
+                Some(libc::EBUSY) => io::Error::new(
+                    io::ErrorKind::ResourceBusy,
+                    "device is busy: partition table not reread",
+                ),
+                _ => why,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Alias for `commit_to_os`: forces the kernel to reread the partition
+    /// table that is already on disk.
+    pub fn reread(&mut self) -> io::Result<()> {
+        self.commit_to_os()
+    }
+}
+
+impl Drop for Disk {
+    fn drop(&mut self) {
+        unsafe { ffi::ped_disk_destroy(self.0) }
+    }
+}
+
+/// The smallest byte count that is a whole number of sectors on both devices,
+/// for `Disk::clone_partition_to`'s chunked transfer.
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
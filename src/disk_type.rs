@@ -0,0 +1,30 @@
+use std::ffi::CString;
+use std::io;
+
+use ffi::{self, PedDiskType};
+
+/// A disk label format known to libparted, such as `"gpt"`, `"msdos"`, or
+/// `"loop"`. Looked up by name and handed to `Disk::new_fresh` to lay down a
+/// brand-new partition table.
+pub struct DiskType(*const PedDiskType);
+
+impl DiskType {
+    /// Looks up a disk label type by name (`ped_disk_type_get`).
+    pub fn get(name: &str) -> io::Result<DiskType> {
+        let cstr = CString::new(name)?;
+        let disk_type = unsafe { ffi::ped_disk_type_get(cstr.as_ptr()) };
+        if disk_type.is_null() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "unknown disk label type",
+            ));
+        }
+
+        Ok(DiskType(disk_type))
+    }
+
+    /// The raw `PedDiskType` pointer, for use by sibling modules in this crate.
+    pub(crate) fn as_ptr(&self) -> *const PedDiskType {
+        self.0
+    }
+}
@@ -0,0 +1,155 @@
+//! Raw `extern "C"` declarations for the subset of libparted's C API that this
+//! crate binds. Types are opaque unless a field is actually read from Rust, in
+//! which case the layout mirrors `<parted/parted.h>`.
+
+use libc::{c_char, c_int, c_short, c_void};
+
+pub type PedSector = i64;
+
+#[repr(C)]
+pub struct PedCHSGeometry {
+    pub cylinders: c_int,
+    pub heads: c_int,
+    pub sectors: c_int,
+}
+
+#[repr(C)]
+pub struct PedDevice {
+    pub next: *mut PedDevice,
+    pub model: *mut c_char,
+    pub path: *mut c_char,
+    pub type_: c_int,
+    pub sector_size: u64,
+    pub phys_sector_size: u64,
+    pub length: PedSector,
+    pub open_count: c_int,
+    pub read_only: c_int,
+    pub external_mode: c_int,
+    pub dirty: c_int,
+    pub boot_dirty: c_int,
+    pub hw_geom: PedCHSGeometry,
+    pub bios_geom: PedCHSGeometry,
+    pub host: c_short,
+    pub did: c_short,
+    pub arch_specific: *mut c_void,
+}
+
+#[repr(C)]
+pub struct PedDiskType {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct PedDisk {
+    pub dev: *mut PedDevice,
+    _opaque: [u8; 0],
+}
+
+#[repr(C)]
+pub struct PedGeometry {
+    pub dev: *mut PedDevice,
+    pub start: PedSector,
+    pub length: PedSector,
+    pub end: PedSector,
+}
+
+#[repr(C)]
+pub struct PedConstraint {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct PedFileSystemType {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct PedPartition {
+    pub prev: *mut PedPartition,
+    pub next: *mut PedPartition,
+    pub disk: *mut PedDisk,
+    pub geom: PedGeometry,
+    pub num: c_int,
+    pub part_type: c_int,
+    _opaque: [u8; 0],
+}
+
+extern "C" {
+    // device.c
+    pub fn ped_device_get(path: *const c_char) -> *mut PedDevice;
+    pub fn ped_device_destroy(dev: *mut PedDevice);
+    pub fn ped_device_open(dev: *mut PedDevice) -> c_int;
+    pub fn ped_device_close(dev: *mut PedDevice) -> c_int;
+    pub fn ped_device_get_optimal_aligned_constraint(dev: *mut PedDevice) -> *mut PedConstraint;
+    pub fn ped_device_get_minimal_aligned_constraint(dev: *mut PedDevice) -> *mut PedConstraint;
+    pub fn ped_device_read(
+        dev: *mut PedDevice,
+        buffer: *mut c_void,
+        start: PedSector,
+        count: PedSector,
+    ) -> c_int;
+    pub fn ped_device_write(
+        dev: *mut PedDevice,
+        buffer: *const c_void,
+        start: PedSector,
+        count: PedSector,
+    ) -> c_int;
+
+    // disk.c
+    pub fn ped_disk_type_get(name: *const c_char) -> *const PedDiskType;
+    pub fn ped_disk_new(dev: *mut PedDevice) -> *mut PedDisk;
+    pub fn ped_disk_new_fresh(dev: *mut PedDevice, disk_type: *const PedDiskType) -> *mut PedDisk;
+    pub fn ped_disk_destroy(disk: *mut PedDisk);
+    pub fn ped_disk_commit(disk: *mut PedDisk) -> c_int;
+    pub fn ped_disk_commit_to_dev(disk: *mut PedDisk) -> c_int;
+    pub fn ped_disk_commit_to_os(disk: *mut PedDisk) -> c_int;
+    pub fn ped_disk_add_partition(
+        disk: *mut PedDisk,
+        part: *mut PedPartition,
+        constraint: *const PedConstraint,
+    ) -> c_int;
+    pub fn ped_disk_get_partition_alignment(disk: *const PedDisk) -> *mut PedConstraint;
+    pub fn ped_disk_next_partition(
+        disk: *const PedDisk,
+        part: *const PedPartition,
+    ) -> *mut PedPartition;
+
+    // geometry.c
+    pub fn ped_geometry_new(
+        dev: *const PedDevice,
+        start: PedSector,
+        length: PedSector,
+    ) -> *mut PedGeometry;
+    pub fn ped_geometry_destroy(geom: *mut PedGeometry);
+    pub fn ped_geometry_duplicate(geom: *const PedGeometry) -> *mut PedGeometry;
+
+    // constraint.c
+    pub fn ped_constraint_any(dev: *const PedDevice) -> *mut PedConstraint;
+    pub fn ped_constraint_exact(geom: *const PedGeometry) -> *mut PedConstraint;
+    pub fn ped_constraint_new_from_max(max: *const PedGeometry) -> *mut PedConstraint;
+    pub fn ped_constraint_intersect(
+        a: *const PedConstraint,
+        b: *const PedConstraint,
+    ) -> *mut PedConstraint;
+    pub fn ped_constraint_destroy(constraint: *mut PedConstraint);
+
+    // filesys.c
+    pub fn ped_file_system_type_get(name: *const c_char) -> *const PedFileSystemType;
+
+    // partition.c
+    pub fn ped_partition_new(
+        disk: *const PedDisk,
+        part_type: c_int,
+        fs_type: *const PedFileSystemType,
+        start: PedSector,
+        end: PedSector,
+    ) -> *mut PedPartition;
+    pub fn ped_partition_destroy(part: *mut PedPartition);
+    pub fn ped_partition_is_flag_available(part: *const PedPartition, flag: c_int) -> c_int;
+    pub fn ped_partition_set_flag(part: *mut PedPartition, flag: c_int, state: c_int) -> c_int;
+    pub fn ped_partition_get_flag(part: *const PedPartition, flag: c_int) -> c_int;
+    pub fn ped_partition_set_name(part: *mut PedPartition, name: *const c_char) -> c_int;
+    pub fn ped_partition_get_name(part: *const PedPartition) -> *const c_char;
+    pub fn ped_partition_set_type_uuid(part: *mut PedPartition, uuid: *const u8) -> c_int;
+    pub fn ped_partition_get_type_uuid(part: *const PedPartition) -> *const u8;
+}
@@ -0,0 +1,29 @@
+use std::ffi::CString;
+use std::io;
+
+use ffi::{self, PedFileSystemType};
+
+/// A file system type known to libparted (e.g. `"ext4"`, `"fat32"`), used to
+/// hint `ped_partition_new` at how a partition should be labeled.
+pub struct FileSystemType(*const PedFileSystemType);
+
+impl FileSystemType {
+    /// Looks up a file system type by name (`ped_file_system_type_get`).
+    pub fn get(name: &str) -> io::Result<FileSystemType> {
+        let cstr = CString::new(name)?;
+        let fs_type = unsafe { ffi::ped_file_system_type_get(cstr.as_ptr()) };
+        if fs_type.is_null() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "unknown file system type",
+            ));
+        }
+
+        Ok(FileSystemType(fs_type))
+    }
+
+    /// The raw `PedFileSystemType` pointer, for use by sibling modules.
+    pub(crate) fn as_ptr(&self) -> *const PedFileSystemType {
+        self.0
+    }
+}
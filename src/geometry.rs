@@ -0,0 +1,76 @@
+use std::io;
+
+use ffi::{self, PedDevice, PedGeometry};
+use Constraint;
+use Device;
+
+/// A region of a device, expressed as a starting sector and a length.
+pub struct Geometry(*mut PedGeometry);
+
+impl Geometry {
+    /// Creates a new geometry describing `length` sectors starting at
+    /// `start` on `device`.
+    pub fn new(device: &Device, start: i64, length: i64) -> io::Result<Geometry> {
+        let geom = unsafe { ffi::ped_geometry_new(device.as_ptr_const(), start, length) };
+        if geom.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Geometry(geom))
+    }
+
+    /// Creates a new geometry against a raw, borrowed `PedDevice` pointer
+    /// (e.g. one read out of another disk's `dev` field, with no owning
+    /// `Device` at hand). Used internally by `Disk::clone_partition_to`.
+    pub(crate) unsafe fn new_raw(dev: *const PedDevice, start: i64, length: i64) -> io::Result<Geometry> {
+        let geom = ffi::ped_geometry_new(dev, start, length);
+        if geom.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Geometry(geom))
+    }
+
+    /// Wraps an already-allocated, owned `PedGeometry`. The caller must have
+    /// checked it for null.
+    pub(crate) unsafe fn from_ptr(geom: *mut PedGeometry) -> Geometry {
+        Geometry(geom)
+    }
+
+    /// The raw `PedGeometry` pointer, for use by sibling modules in this crate.
+    pub(crate) fn as_ptr(&self) -> *const PedGeometry {
+        self.0
+    }
+
+    /// The first sector of the region.
+    pub fn start(&self) -> i64 {
+        unsafe { (*self.0).start }
+    }
+
+    /// The number of sectors spanned by the region.
+    pub fn length(&self) -> i64 {
+        unsafe { (*self.0).length }
+    }
+
+    /// The last sector of the region.
+    pub fn end(&self) -> i64 {
+        unsafe { (*self.0).end }
+    }
+
+    /// A constraint that pins a new partition to exactly this region
+    /// (`ped_constraint_exact`).
+    pub fn exact(&self) -> Option<Constraint> {
+        let constraint = unsafe { ffi::ped_constraint_exact(self.0) };
+        if constraint.is_null() {
+            None
+        } else {
+            Some(unsafe { Constraint::from_ptr(constraint) })
+        }
+    }
+}
+
+impl Drop for Geometry {
+    fn drop(&mut self) {
+        unsafe { ffi::ped_geometry_destroy(self.0) }
+    }
+}
@@ -0,0 +1,31 @@
+//! Safe bindings to [libparted](https://www.gnu.org/software/parted/), the
+//! library behind GNU Parted.
+//!
+//! This crate wraps the subset of libparted's C API needed to read, create,
+//! and modify partition tables: `Device` for the underlying block device,
+//! `Disk` for its partition table, `Partition` and `Geometry` for individual
+//! entries, and `Constraint` for the placement rules libparted enforces
+//! when a partition is added.
+
+extern crate libc;
+
+mod constraint;
+mod device;
+mod disk;
+mod disk_type;
+mod ffi;
+mod fs_type;
+mod geometry;
+mod partition;
+mod partition_flag;
+mod partition_type;
+
+pub use constraint::Constraint;
+pub use device::Device;
+pub use disk::Disk;
+pub use disk_type::DiskType;
+pub use fs_type::FileSystemType;
+pub use geometry::Geometry;
+pub use partition::Partition;
+pub use partition_flag::PartitionFlag;
+pub use partition_type::PartitionType;
@@ -0,0 +1,164 @@
+use std::ffi::{CStr, CString};
+use std::io;
+
+use ffi::{self, PedPartition};
+use Disk;
+use FileSystemType;
+use PartitionFlag;
+use PartitionType;
+
+/// A single entry in a disk's partition table.
+///
+/// Once a `Partition` has been handed to `Disk::add_partition`, libparted
+/// owns it: it is freed when the `Disk` is destroyed, not before. A
+/// `Partition` tracks whether that handoff has happened so its own `Drop`
+/// doesn't free memory the disk still owns.
+pub struct Partition {
+    ptr: *mut PedPartition,
+    added: bool,
+}
+
+impl Partition {
+    /// Creates a new partition of `part_type` spanning `length` sectors
+    /// starting at `start`, within `disk`. The partition is not added to the
+    /// disk's table until passed to `Disk::add_partition`.
+    pub fn new(
+        disk: &mut Disk,
+        part_type: PartitionType,
+        fs_type: Option<&FileSystemType>,
+        start: i64,
+        length: i64,
+    ) -> io::Result<Partition> {
+        let fs_type_ptr = fs_type.map_or(::std::ptr::null(), |fs| fs.as_ptr());
+        let part = unsafe {
+            ffi::ped_partition_new(
+                disk.as_ptr_const(),
+                part_type as i32,
+                fs_type_ptr,
+                start,
+                start + length - 1,
+            )
+        };
+
+        if part.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Partition { ptr: part, added: false })
+    }
+
+    /// Wraps a `PedPartition` already owned by a disk's table (e.g. one
+    /// walked via `ped_disk_next_partition`). The disk frees it, not us, so
+    /// this starts out `added`. Used internally by `Disk::partitions`.
+    pub(crate) unsafe fn from_borrowed_ptr(ptr: *mut PedPartition) -> Partition {
+        Partition { ptr, added: true }
+    }
+
+    /// The raw `PedPartition` pointer, for use by sibling modules in this crate.
+    pub(crate) fn as_ptr_mut(&mut self) -> *mut PedPartition {
+        self.ptr
+    }
+
+    /// Marks this partition as owned by a disk (`ped_disk_add_partition` took
+    /// it over), so `Drop` no longer frees it. Called by `Disk::add_partition`
+    /// once the handoff has actually succeeded.
+    pub(crate) fn mark_added(&mut self) {
+        self.added = true;
+    }
+
+    /// The first sector of the partition.
+    pub fn start(&self) -> i64 {
+        unsafe { (*self.ptr).geom.start }
+    }
+
+    /// The number of sectors the partition spans.
+    pub fn length(&self) -> i64 {
+        unsafe { (*self.ptr).geom.length }
+    }
+
+    /// The role this partition plays within its disk label.
+    pub fn part_type(&self) -> PartitionType {
+        unsafe { PartitionType::from_raw((*self.ptr).part_type) }
+    }
+
+    /// Whether `flag` applies to this partition's type (not every flag is
+    /// meaningful on every disk label or partition type).
+    pub fn is_flag_available(&self, flag: PartitionFlag) -> bool {
+        unsafe { ffi::ped_partition_is_flag_available(self.ptr, flag as i32) != 0 }
+    }
+
+    /// Whether `flag` is currently set on this partition.
+    pub fn flag(&self, flag: PartitionFlag) -> bool {
+        unsafe { ffi::ped_partition_get_flag(self.ptr, flag as i32) != 0 }
+    }
+
+    /// Enables or disables `flag` on this partition.
+    pub fn set_flag(&mut self, flag: PartitionFlag, state: bool) -> io::Result<()> {
+        let result = unsafe { ffi::ped_partition_set_flag(self.ptr, flag as i32, state as i32) };
+        if result == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// Sets the partition's human-readable name. Supported by GPT, Mac, and
+    /// a handful of other disk labels; ignored by labels that don't support
+    /// per-partition names (e.g. MBR).
+    pub fn set_name(&mut self, name: &str) -> io::Result<()> {
+        let cstr = CString::new(name)?;
+        let result = unsafe { ffi::ped_partition_set_name(self.ptr, cstr.as_ptr()) };
+        if result == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// The partition's human-readable name, if the disk label supports one.
+    pub fn name(&self) -> Option<String> {
+        let ptr = unsafe { ffi::ped_partition_get_name(self.ptr) };
+        if ptr.is_null() {
+            return None;
+        }
+
+        Some(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+    }
+
+    /// Sets the partition's GPT type GUID (e.g. the well-known Linux
+    /// filesystem, EFI System, or swap GUIDs), as raw GUID bytes.
+    ///
+    /// Requires libparted >= 3.4 (pinned in `build.rs`); ignored on disk
+    /// labels that don't carry a per-partition type GUID (e.g. MBR).
+    pub fn set_type_uuid(&mut self, uuid: &[u8; 16]) -> io::Result<()> {
+        let result = unsafe { ffi::ped_partition_set_type_uuid(self.ptr, uuid.as_ptr()) };
+        if result == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// The partition's GPT type GUID, if the disk label supports one.
+    ///
+    /// Requires libparted >= 3.4 (pinned in `build.rs`). Trusts libparted to
+    /// hand back a pointer to a full 16-byte GUID, per that version's ABI.
+    pub fn type_uuid(&self) -> Option<[u8; 16]> {
+        let ptr = unsafe { ffi::ped_partition_get_type_uuid(self.ptr) };
+        if ptr.is_null() {
+            return None;
+        }
+
+        let mut uuid = [0u8; 16];
+        uuid.copy_from_slice(unsafe { ::std::slice::from_raw_parts(ptr, 16) });
+        Some(uuid)
+    }
+}
+
+impl Drop for Partition {
+    fn drop(&mut self) {
+        if !self.added {
+            unsafe { ffi::ped_partition_destroy(self.ptr) }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/// Per-partition boolean flags, queried and toggled via `ped_partition_*_flag`.
+///
+/// Mirrors `PedPartitionFlag` from `<parted/disk.h>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum PartitionFlag {
+    PED_PARTITION_BOOT = 1,
+    PED_PARTITION_ROOT = 2,
+    PED_PARTITION_SWAP = 3,
+    PED_PARTITION_HIDDEN = 4,
+    PED_PARTITION_RAID = 5,
+    PED_PARTITION_LVM = 6,
+    PED_PARTITION_LBA = 7,
+    PED_PARTITION_HPSERVICE = 8,
+    PED_PARTITION_PALO = 9,
+    PED_PARTITION_PREP = 10,
+    PED_PARTITION_MSFT_RESERVED = 11,
+    PED_PARTITION_BIOS_GRUB = 12,
+    PED_PARTITION_APPLE_TV_RECOVERY = 13,
+    PED_PARTITION_DIAG = 14,
+    PED_PARTITION_LEGACY_BOOT = 15,
+    PED_PARTITION_MSFT_DATA = 16,
+    PED_PARTITION_IRST = 17,
+    PED_PARTITION_ESP = 18,
+}
+
+impl PartitionFlag {
+    /// Every flag this crate knows about, for code that needs to enumerate
+    /// them (e.g. copying a partition's flags onto a new one).
+    pub(crate) const ALL: [PartitionFlag; 18] = [
+        PartitionFlag::PED_PARTITION_BOOT,
+        PartitionFlag::PED_PARTITION_ROOT,
+        PartitionFlag::PED_PARTITION_SWAP,
+        PartitionFlag::PED_PARTITION_HIDDEN,
+        PartitionFlag::PED_PARTITION_RAID,
+        PartitionFlag::PED_PARTITION_LVM,
+        PartitionFlag::PED_PARTITION_LBA,
+        PartitionFlag::PED_PARTITION_HPSERVICE,
+        PartitionFlag::PED_PARTITION_PALO,
+        PartitionFlag::PED_PARTITION_PREP,
+        PartitionFlag::PED_PARTITION_MSFT_RESERVED,
+        PartitionFlag::PED_PARTITION_BIOS_GRUB,
+        PartitionFlag::PED_PARTITION_APPLE_TV_RECOVERY,
+        PartitionFlag::PED_PARTITION_DIAG,
+        PartitionFlag::PED_PARTITION_LEGACY_BOOT,
+        PartitionFlag::PED_PARTITION_MSFT_DATA,
+        PartitionFlag::PED_PARTITION_IRST,
+        PartitionFlag::PED_PARTITION_ESP,
+    ];
+}
@@ -0,0 +1,28 @@
+/// The role a partition plays within its disk label.
+///
+/// Mirrors `PedPartitionType` from `<parted/disk.h>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum PartitionType {
+    PED_PARTITION_NORMAL = 0,
+    PED_PARTITION_LOGICAL = 1,
+    PED_PARTITION_EXTENDED = 2,
+    PED_PARTITION_FREESPACE = 4,
+    PED_PARTITION_METADATA = 8,
+    PED_PARTITION_PROTECTED = 16,
+}
+
+impl PartitionType {
+    /// Maps a raw `PedPartitionType` value back to its Rust enum, for
+    /// partitions read back from libparted rather than constructed by us.
+    pub(crate) fn from_raw(value: i32) -> PartitionType {
+        match value {
+            1 => PartitionType::PED_PARTITION_LOGICAL,
+            2 => PartitionType::PED_PARTITION_EXTENDED,
+            4 => PartitionType::PED_PARTITION_FREESPACE,
+            8 => PartitionType::PED_PARTITION_METADATA,
+            16 => PartitionType::PED_PARTITION_PROTECTED,
+            _ => PartitionType::PED_PARTITION_NORMAL,
+        }
+    }
+}